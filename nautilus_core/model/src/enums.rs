@@ -0,0 +1,204 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Enumerations used throughout the `nautilus_model` crate.
+
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, EnumString};
+
+/// The canonical reason an order modify/cancel request was rejected by a venue or the engine.
+///
+/// Strategies and risk engines should branch on this stable code rather than pattern-matching
+/// the free-form, venue-specific `reason` text carried alongside it.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Display,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum OrderRejectReason {
+    /// The order referenced by the request is unknown to the venue (already filled, canceled, or never existed).
+    OrderDoesNotExist,
+    /// The order referenced by the request has already reached a closed (terminal) state.
+    OrderAlreadyClosed,
+    /// The requested price is invalid for the instrument (tick size, bounds, or type).
+    InvalidPrice,
+    /// The requested quantity is invalid for the instrument (lot size, bounds, or precision).
+    InvalidQuantity,
+    /// The request was throttled by the venue's rate limiter and may succeed on retry.
+    RateLimited,
+    /// The account does not have sufficient margin to support the requested change.
+    InsufficientMargin,
+    /// A post-only order would have crossed the book and was rejected instead of filling as taker.
+    PostOnlyWouldCross,
+    /// The venue rejected the request for a reason not otherwise classified here.
+    VenueRejected,
+    /// The reason could not be classified from the information available.
+    #[default]
+    Other,
+}
+
+/// The provenance of an order event: what actually triggered it to be generated.
+///
+/// This is a richer alternative to a single `reconciliation` flag, letting logs, backtests, and
+/// the event indexer (see [`OrderEventRecord`](crate::events::order::record::OrderEventRecord))
+/// separate trader-initiated state transitions from system-generated ones.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Display,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum OrderEventSource {
+    /// The event resulted directly from a trader's manual action, or no richer provenance is known.
+    #[default]
+    Manual,
+    /// The event resulted from strategy-internal order management logic (not a direct user action).
+    Strategy,
+    /// The event resulted from an emulated or contingent order's trigger firing (e.g. OCO, OTO).
+    ContingencyTrigger,
+    /// The event resulted from an order reaching its expiration time.
+    ExpiryTrigger,
+    /// The event resulted from a state reconciliation pass against the venue.
+    Reconciliation,
+}
+
+impl OrderRejectReason {
+    /// Returns whether an order action which was rejected for this reason is safe to retry.
+    ///
+    /// Only reasons known to be transient (rate limiting) are retryable. Everything else,
+    /// including the `VenueRejected`/`Other` catch-alls, fails closed: an unclassified or
+    /// generically-rejected request must not be blindly resubmitted against the venue.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimited => true,
+            Self::OrderDoesNotExist
+            | Self::OrderAlreadyClosed
+            | Self::InvalidPrice
+            | Self::InvalidQuantity
+            | Self::InsufficientMargin
+            | Self::PostOnlyWouldCross
+            | Self::VenueRejected
+            | Self::Other => false,
+        }
+    }
+
+    /// Classifies a raw, venue-specific reject message into a canonical [`OrderRejectReason`].
+    ///
+    /// The match is performed case-insensitively against common venue phrasing, with spaces and
+    /// hyphens normalized to underscores first so that both `"Order Does Not Exist"` and
+    /// `"ORDER-DOES-NOT-EXIST"` resolve the same way. Specific and transient reasons
+    /// (`RATE_LIMIT`, `POST_ONLY`, `INSUFFICIENT_MARGIN`) are matched *before* the generic
+    /// `PRICE`/`QUANTITY` catch-alls, so a message like `"order quantity exceeds rate limit"` or
+    /// `"post-only would cross, price 100.5"` resolves to the specific reason rather than being
+    /// shadowed by the coarser one. Anything unrecognized falls back to [`OrderRejectReason::Other`].
+    #[must_use]
+    pub fn classify(raw: &str) -> Self {
+        let raw = raw
+            .to_ascii_uppercase()
+            .replace([' ', '-'], "_");
+        if raw.contains("DOES_NOT_EXIST") || raw.contains("UNKNOWN_ORDER") {
+            Self::OrderDoesNotExist
+        } else if raw.contains("ALREADY_CLOSED") || raw.contains("ALREADY_FILLED") {
+            Self::OrderAlreadyClosed
+        } else if raw.contains("RATE_LIMIT") || raw.contains("TOO_MANY_REQUESTS") {
+            Self::RateLimited
+        } else if raw.contains("POST_ONLY") {
+            Self::PostOnlyWouldCross
+        } else if raw.contains("INSUFFICIENT_MARGIN") || raw.contains("MARGIN") {
+            Self::InsufficientMargin
+        } else if raw.contains("INVALID_PRICE") || raw.contains("PRICE") {
+            Self::InvalidPrice
+        } else if raw.contains("INVALID_QUANTITY") || raw.contains("QUANTITY") {
+            Self::InvalidQuantity
+        } else if raw.contains("REJECTED") {
+            Self::VenueRejected
+        } else {
+            Self::Other
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("RATE_LIMIT_EXCEEDED", OrderRejectReason::RateLimited)]
+    #[case("Rate limit exceeded", OrderRejectReason::RateLimited)]
+    #[case("order does not exist", OrderRejectReason::OrderDoesNotExist)]
+    #[case("Order already closed", OrderRejectReason::OrderAlreadyClosed)]
+    #[case("Insufficient margin for order", OrderRejectReason::InsufficientMargin)]
+    #[case("Post-only would cross, price 100.5", OrderRejectReason::PostOnlyWouldCross)]
+    #[case("Order quantity exceeds rate limit", OrderRejectReason::RateLimited)]
+    #[case("something unexpected", OrderRejectReason::Other)]
+    fn test_classify(#[case] raw: &str, #[case] expected: OrderRejectReason) {
+        assert_eq!(OrderRejectReason::classify(raw), expected);
+    }
+
+    #[rstest]
+    #[case(OrderRejectReason::RateLimited, true)]
+    #[case(OrderRejectReason::VenueRejected, false)]
+    #[case(OrderRejectReason::Other, false)]
+    #[case(OrderRejectReason::OrderDoesNotExist, false)]
+    #[case(OrderRejectReason::OrderAlreadyClosed, false)]
+    fn test_is_retryable(#[case] reason: OrderRejectReason, #[case] expected: bool) {
+        assert_eq!(reason.is_retryable(), expected);
+    }
+}
@@ -0,0 +1,162 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod modify_rejected;
+pub mod record;
+pub mod retry;
+pub mod sink;
+
+#[cfg(test)]
+pub mod stubs;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use ustr::Ustr;
+
+use crate::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderEventSource, OrderRejectReason, OrderSide, OrderType,
+        TimeInForce, TrailingOffsetType, TriggerType,
+    },
+    events::order::record::{OrderEventRecord, ORDER_EVENT_RECORD_SCHEMA_VERSION},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// A common interface over every concrete order lifecycle event (submitted, accepted, rejected,
+/// filled, and so on), allowing consumers to handle a heterogeneous stream of events through a
+/// single trait object rather than downcasting to each concrete type.
+pub trait OrderEvent: 'static + Send {
+    /// Returns the unique identifier for this event.
+    fn id(&self) -> UUID4;
+    /// Returns the concrete event type name (e.g. `"OrderModifyRejected"`).
+    fn kind(&self) -> &str;
+    fn order_type(&self) -> Option<OrderType>;
+    fn order_side(&self) -> Option<OrderSide>;
+    fn trader_id(&self) -> TraderId;
+    fn strategy_id(&self) -> StrategyId;
+    fn instrument_id(&self) -> InstrumentId;
+    fn client_order_id(&self) -> ClientOrderId;
+    fn reason(&self) -> Option<Ustr>;
+    /// Returns the canonical classification of [`Self::reason`], if this event carries one.
+    fn reason_code(&self) -> OrderRejectReason {
+        OrderRejectReason::Other
+    }
+    /// Returns the provenance of this event: what triggered it to be generated.
+    fn event_source(&self) -> OrderEventSource {
+        OrderEventSource::Manual
+    }
+    fn quantity(&self) -> Option<Quantity>;
+    fn time_in_force(&self) -> Option<TimeInForce>;
+    fn post_only(&self) -> Option<bool>;
+    fn reduce_only(&self) -> Option<bool>;
+    fn quote_quantity(&self) -> Option<bool>;
+    fn reconciliation(&self) -> bool;
+    fn price(&self) -> Option<Price>;
+    fn trigger_price(&self) -> Option<Price>;
+    fn trigger_type(&self) -> Option<TriggerType>;
+    fn limit_offset(&self) -> Option<Price>;
+    fn trailing_offset(&self) -> Option<Price>;
+    fn trailing_offset_type(&self) -> Option<TrailingOffsetType>;
+    fn expire_time(&self) -> Option<UnixNanos>;
+    fn display_qty(&self) -> Option<Quantity>;
+    fn emulation_trigger(&self) -> Option<TriggerType>;
+    fn trigger_instrument_id(&self) -> Option<InstrumentId>;
+    fn contingency_type(&self) -> Option<ContingencyType>;
+    fn order_list_id(&self) -> Option<OrderListId>;
+    fn linked_order_ids(&self) -> Option<Vec<ClientOrderId>>;
+    fn parent_order_id(&self) -> Option<ClientOrderId>;
+    fn exec_algorithm_id(&self) -> Option<ExecAlgorithmId>;
+    fn exec_spawn_id(&self) -> Option<ClientOrderId>;
+    fn venue_order_id(&self) -> Option<VenueOrderId>;
+    fn account_id(&self) -> Option<AccountId>;
+    fn ts_event(&self) -> UnixNanos;
+    fn ts_init(&self) -> UnixNanos;
+
+    /// Returns the venue-assigned identifier for the trade that filled this order, if any.
+    fn trade_id(&self) -> Option<TradeId> {
+        None
+    }
+    /// Returns the settlement currency associated with this event, if any.
+    fn currency(&self) -> Option<Currency> {
+        None
+    }
+    /// Returns whether this event's fill was the maker or taker side of the trade, if any.
+    fn liquidity_side(&self) -> Option<LiquiditySide> {
+        None
+    }
+    /// Returns the price of the last fill this event represents, if any.
+    fn last_px(&self) -> Option<Price> {
+        None
+    }
+    /// Returns the quantity of the last fill this event represents, if any.
+    fn last_qty(&self) -> Option<Quantity> {
+        None
+    }
+
+    /// Flattens this event into a variant-agnostic [`OrderEventRecord`] suitable for indexing.
+    ///
+    /// Implemented once here in terms of the other trait accessors, so concrete events never
+    /// need to duplicate the flattening logic.
+    fn to_record(&self) -> OrderEventRecord {
+        OrderEventRecord {
+            schema_version: ORDER_EVENT_RECORD_SCHEMA_VERSION,
+            kind: Ustr::from(self.kind()),
+            trader_id: self.trader_id(),
+            strategy_id: self.strategy_id(),
+            instrument_id: self.instrument_id(),
+            client_order_id: self.client_order_id(),
+            venue_order_id: self.venue_order_id(),
+            account_id: self.account_id(),
+            event_id: self.id().to_string(),
+            ts_event: self.ts_event(),
+            ts_init: self.ts_init(),
+            order_type: self.order_type(),
+            order_side: self.order_side(),
+            reason: self.reason(),
+            reason_code: self.reason_code(),
+            event_source: self.event_source(),
+            quantity: self.quantity(),
+            time_in_force: self.time_in_force(),
+            post_only: self.post_only(),
+            reduce_only: self.reduce_only(),
+            quote_quantity: self.quote_quantity(),
+            reconciliation: self.reconciliation(),
+            price: self.price(),
+            trigger_price: self.trigger_price(),
+            trigger_type: self.trigger_type(),
+            limit_offset: self.limit_offset(),
+            trailing_offset: self.trailing_offset(),
+            trailing_offset_type: self.trailing_offset_type(),
+            expire_time: self.expire_time(),
+            display_qty: self.display_qty(),
+            emulation_trigger: self.emulation_trigger(),
+            trigger_instrument_id: self.trigger_instrument_id(),
+            contingency_type: self.contingency_type(),
+            order_list_id: self.order_list_id(),
+            parent_order_id: self.parent_order_id(),
+            exec_algorithm_id: self.exec_algorithm_id(),
+            exec_spawn_id: self.exec_spawn_id(),
+            trade_id: self.trade_id(),
+            currency: self.currency(),
+            liquidity_side: self.liquidity_side(),
+            last_px: self.last_px(),
+            last_qty: self.last_qty(),
+        }
+    }
+}
@@ -21,14 +21,17 @@ use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::{
-    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, OrderEventSource, OrderRejectReason, OrderSide, OrderType,
+        TimeInForce, TrailingOffsetType, TriggerType,
+    },
     events::order::OrderEvent,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
-        trader_id::TraderId, venue_order_id::VenueOrderId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
     },
-    types::{price::Price, quantity::Quantity},
+    types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
 #[repr(C)]
@@ -45,10 +48,12 @@ pub struct OrderModifyRejected {
     pub instrument_id: InstrumentId,
     pub client_order_id: ClientOrderId,
     pub reason: Ustr,
+    pub reason_code: OrderRejectReason,
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    pub event_source: OrderEventSource,
     pub venue_order_id: Option<VenueOrderId>,
     pub account_id: Option<AccountId>,
 }
@@ -61,10 +66,12 @@ impl OrderModifyRejected {
         instrument_id: InstrumentId,
         client_order_id: ClientOrderId,
         reason: Ustr,
+        reason_code: OrderRejectReason,
         event_id: UUID4,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
         reconciliation: bool,
+        event_source: OrderEventSource,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
     ) -> anyhow::Result<Self> {
@@ -74,10 +81,12 @@ impl OrderModifyRejected {
             instrument_id,
             client_order_id,
             reason,
+            reason_code,
             event_id,
             ts_event,
             ts_init,
             reconciliation: u8::from(reconciliation),
+            event_source,
             venue_order_id,
             account_id,
         })
@@ -156,6 +165,14 @@ impl OrderEvent for OrderModifyRejected {
         Some(self.reason)
     }
 
+    fn reason_code(&self) -> OrderRejectReason {
+        self.reason_code
+    }
+
+    fn event_source(&self) -> OrderEventSource {
+        self.event_source
+    }
+
     fn quantity(&self) -> Option<Quantity> {
         None
     }
@@ -259,6 +276,26 @@ impl OrderEvent for OrderModifyRejected {
     fn ts_init(&self) -> UnixNanos {
         self.ts_init
     }
+
+    fn trade_id(&self) -> Option<TradeId> {
+        None
+    }
+
+    fn currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn liquidity_side(&self) -> Option<LiquiditySide> {
+        None
+    }
+
+    fn last_px(&self) -> Option<Price> {
+        None
+    }
+
+    fn last_qty(&self) -> Option<Quantity> {
+        None
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -268,7 +305,7 @@ impl OrderEvent for OrderModifyRejected {
 mod tests {
     use rstest::rstest;
 
-    use crate::events::order::{modify_rejected::OrderModifyRejected, stubs::*};
+    use crate::events::order::{modify_rejected::OrderModifyRejected, stubs::*, OrderEvent};
 
     #[rstest]
     fn test_order_modified_rejected(order_modify_rejected: OrderModifyRejected) {
@@ -279,4 +316,32 @@ mod tests {
             venue_order_id=001, account_id=SIM-001, reason='ORDER_DOES_NOT_EXIST', ts_event=0)"
         );
     }
+
+    #[rstest]
+    fn test_order_modify_rejected_reason_code(order_modify_rejected: OrderModifyRejected) {
+        assert_eq!(
+            order_modify_rejected.reason_code,
+            crate::enums::OrderRejectReason::OrderDoesNotExist
+        );
+        assert!(!order_modify_rejected.reason_code.is_retryable());
+    }
+
+    #[rstest]
+    fn test_order_modify_rejected_event_source(order_modify_rejected: OrderModifyRejected) {
+        assert_eq!(
+            order_modify_rejected.event_source(),
+            crate::enums::OrderEventSource::Manual
+        );
+    }
+
+    #[rstest]
+    fn test_order_modify_rejected_has_no_execution_details(
+        order_modify_rejected: OrderModifyRejected,
+    ) {
+        assert_eq!(order_modify_rejected.trade_id(), None);
+        assert_eq!(order_modify_rejected.currency(), None);
+        assert_eq!(order_modify_rejected.liquidity_side(), None);
+        assert_eq!(order_modify_rejected.last_px(), None);
+        assert_eq!(order_modify_rejected.last_qty(), None);
+    }
 }
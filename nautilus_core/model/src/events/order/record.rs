@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A flat, indexer-friendly representation of any [`OrderEvent`](super::OrderEvent), used to
+//! stream the full order event history to external consumers without reconstructing it from the
+//! concrete event variants.
+
+use nautilus_core::nanos::UnixNanos;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderEventSource, OrderRejectReason, OrderSide, OrderType,
+        TimeInForce, TrailingOffsetType, TriggerType,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// The current [`OrderEventRecord`] schema version, bumped whenever a field is added, removed,
+/// or reinterpreted so that downstream consumers can detect incompatible rows.
+pub const ORDER_EVENT_RECORD_SCHEMA_VERSION: u8 = 1;
+
+/// A versioned, variant-agnostic row derived from any [`OrderEvent`](super::OrderEvent).
+///
+/// Every field that is only meaningful for some event kinds is `Option`-wrapped, so a consumer
+/// can index the complete order event history as one uniform table without downcasting to the
+/// concrete event type first. `linked_order_ids` is intentionally omitted: it is a variable-length
+/// list rather than a scalar, and does not flatten into a fixed-width row.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderEventRecord {
+    pub schema_version: u8,
+    pub kind: Ustr,
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub account_id: Option<AccountId>,
+    pub event_id: String,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+    pub order_type: Option<OrderType>,
+    pub order_side: Option<OrderSide>,
+    pub reason: Option<Ustr>,
+    pub reason_code: OrderRejectReason,
+    pub event_source: OrderEventSource,
+    pub quantity: Option<Quantity>,
+    pub time_in_force: Option<TimeInForce>,
+    pub post_only: Option<bool>,
+    pub reduce_only: Option<bool>,
+    pub quote_quantity: Option<bool>,
+    pub reconciliation: bool,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub trigger_type: Option<TriggerType>,
+    pub limit_offset: Option<Price>,
+    pub trailing_offset: Option<Price>,
+    pub trailing_offset_type: Option<TrailingOffsetType>,
+    pub expire_time: Option<UnixNanos>,
+    pub display_qty: Option<Quantity>,
+    pub emulation_trigger: Option<TriggerType>,
+    pub trigger_instrument_id: Option<InstrumentId>,
+    pub contingency_type: Option<ContingencyType>,
+    pub order_list_id: Option<OrderListId>,
+    pub parent_order_id: Option<ClientOrderId>,
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    pub exec_spawn_id: Option<ClientOrderId>,
+    pub trade_id: Option<TradeId>,
+    pub currency: Option<Currency>,
+    pub liquidity_side: Option<LiquiditySide>,
+    pub last_px: Option<Price>,
+    pub last_qty: Option<Quantity>,
+}
@@ -0,0 +1,326 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Automatic resubmission of modify/cancel requests rejected for a transient reason.
+//!
+//! [`OrderRetryPolicy`] consumes rejection events (e.g. [`OrderModifyRejected`](super::modify_rejected::OrderModifyRejected))
+//! classified via [`OrderRejectReason::is_retryable`](crate::enums::OrderRejectReason::is_retryable)
+//! and decides whether, and when, the original request should be re-issued. It does not perform the
+//! resubmission itself (that is the execution engine's job) - it only tracks attempt state and
+//! yields a decision, so the engine stays the single place that talks to a venue.
+
+use std::collections::HashMap;
+
+use nautilus_core::nanos::UnixNanos;
+
+use crate::{
+    events::order::OrderEvent,
+    identifiers::{client_order_id::ClientOrderId, venue_order_id::VenueOrderId},
+};
+
+/// Identifies the order a retry state belongs to.
+///
+/// Keyed on both the client and venue order identifiers (rather than just the client order id)
+/// because a single `client_order_id` can, across its lifetime, be acknowledged under more than
+/// one `venue_order_id` (e.g. after a venue-side replace); tracking both keeps retry state scoped
+/// to the specific venue-side order instance a rejection was reported against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct RetryKey {
+    client_order_id: ClientOrderId,
+    venue_order_id: Option<VenueOrderId>,
+}
+
+/// Configuration for [`OrderRetryPolicy`] exponential backoff.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicyConfig {
+    /// The base delay in nanoseconds, doubled per attempt (the first retry waits `2 * base_delay_ns`).
+    pub base_delay_ns: u64,
+    /// The maximum exponential delay in nanoseconds, regardless of attempt count (jitter is added after this cap).
+    pub max_delay_ns: u64,
+    /// The maximum number of resubmission attempts before giving up.
+    pub max_attempts: u32,
+    /// The maximum jitter in nanoseconds added to (or subtracted from) each computed delay.
+    pub jitter_ns: u64,
+}
+
+impl Default for RetryPolicyConfig {
+    fn default() -> Self {
+        Self {
+            base_delay_ns: 100_000_000,       // 100ms
+            max_delay_ns: 30_000_000_000,      // 30s
+            max_attempts: 5,
+            jitter_ns: 50_000_000,             // 50ms
+        }
+    }
+}
+
+/// The outcome of feeding a rejection event into an [`OrderRetryPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// The request should be re-issued no earlier than `retry_at`.
+    Resubmit { attempt: u32, retry_at: UnixNanos },
+    /// The reject reason was not retryable, or the rejection is at or before the order's
+    /// high-water mark, so no further action should be taken.
+    NonRetryable,
+    /// The attempt budget has been exhausted; the engine should emit a terminal failure event.
+    AttemptsExhausted,
+}
+
+/// Per-order retry bookkeeping: attempts spent and the most recent event observed.
+///
+/// `high_water_ts` is `None` until the first event for the key has been observed, so a
+/// legitimate first rejection with `ts_event == 0` is never mistaken for a stale one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct RetryState {
+    attempt: u32,
+    high_water_ts: Option<UnixNanos>,
+}
+
+/// Tracks in-flight modify/cancel resubmissions and decides when (and whether) to retry.
+///
+/// Idempotency is preserved by recording a monotonic `ts_event` high-water mark per
+/// [`RetryKey`]: a rejection event older than (or equal to) an already-observed later event for
+/// the same order is ignored, since the order is no longer in the state the rejection describes.
+/// The high-water mark also advances on every `Resubmit` decision, so out-of-order or duplicate
+/// rejection deliveries are fenced as well.
+///
+/// `states` holds one entry per order *currently* being retried, not one per order ever seen:
+/// an entry is evicted as soon as its key reaches a definitively terminal outcome (a
+/// non-retryable rejection, attempts exhausted, or [`Self::observe_terminal_event`]), so the map
+/// stays bounded to in-flight orders in a long-running engine rather than growing for the
+/// lifetime of the process.
+#[derive(Debug, Default)]
+pub struct OrderRetryPolicy {
+    config: RetryPolicyConfig,
+    states: HashMap<RetryKey, RetryState>,
+}
+
+impl OrderRetryPolicy {
+    /// Creates a new [`OrderRetryPolicy`] with the given configuration.
+    #[must_use]
+    pub fn new(config: RetryPolicyConfig) -> Self {
+        Self {
+            config,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Records that `client_order_id`/`venue_order_id` has reached a definitively terminal state
+    /// (fully filled, canceled, expired, or otherwise closed) and evicts its retry bookkeeping.
+    ///
+    /// Callers must not route any further events for this order into [`Self::on_reject`]
+    /// afterwards: once an order is terminal there is nothing left to retry, so rather than keep
+    /// fencing state around "just in case" a late rejection arrives, we drop it outright and rely
+    /// on the engine not to deliver one.
+    pub fn observe_terminal_event(
+        &mut self,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+    ) {
+        let key = RetryKey {
+            client_order_id,
+            venue_order_id,
+        };
+        self.states.remove(&key);
+    }
+
+    /// Evaluates a modify/cancel rejection event and returns the resulting [`RetryDecision`].
+    ///
+    /// `jitter_sample` receives a value in `[0.0, 1.0)` used to derive the jitter component, so
+    /// the caller controls the randomness source (and tests can make it deterministic).
+    pub fn on_reject(
+        &mut self,
+        event: &dyn OrderEvent,
+        now: UnixNanos,
+        jitter_sample: f64,
+    ) -> RetryDecision {
+        let reason_code = event.reason_code();
+        let key = RetryKey {
+            client_order_id: event.client_order_id(),
+            venue_order_id: event.venue_order_id(),
+        };
+
+        if !reason_code.is_retryable() {
+            // Terminal for retry purposes: no resubmission will ever be attempted for this
+            // rejection, so there is nothing left to track for this key.
+            self.states.remove(&key);
+            return RetryDecision::NonRetryable;
+        }
+
+        let state = self.states.entry(key).or_default();
+
+        if state.high_water_ts.is_some_and(|hw| event.ts_event() <= hw) {
+            // A later event (an earlier resubmit decision) for this order has already been
+            // observed; stand down. The entry is left in place since this order is still
+            // in-flight, not terminal.
+            return RetryDecision::NonRetryable;
+        }
+
+        if state.attempt >= self.config.max_attempts {
+            self.states.remove(&key);
+            return RetryDecision::AttemptsExhausted;
+        }
+
+        state.attempt += 1;
+        state.high_water_ts = Some(event.ts_event());
+        let attempt = state.attempt;
+        let delay_ns = self.compute_delay_ns(attempt, jitter_sample);
+
+        RetryDecision::Resubmit {
+            attempt,
+            retry_at: UnixNanos::from(now.as_u64().saturating_add(delay_ns)),
+        }
+    }
+
+    /// Computes `min(base * 2^attempt, max) + jitter`, where `jitter` is `jitter_sample` scaled
+    /// into `[-jitter_ns, +jitter_ns]` and clamped to never produce a negative delay.
+    fn compute_delay_ns(&self, attempt: u32, jitter_sample: f64) -> u64 {
+        let exp_delay = self
+            .config
+            .base_delay_ns
+            .saturating_mul(1u64 << attempt.min(32))
+            .min(self.config.max_delay_ns);
+
+        let jitter_range = self.config.jitter_ns as f64;
+        let jitter = ((jitter_sample.clamp(0.0, 1.0) * 2.0 - 1.0) * jitter_range) as i64;
+
+        exp_delay.saturating_add_signed(jitter)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::{modify_rejected::OrderModifyRejected, stubs::*};
+
+    fn test_policy() -> OrderRetryPolicy {
+        OrderRetryPolicy::new(RetryPolicyConfig {
+            base_delay_ns: 100,
+            max_delay_ns: 10_000,
+            max_attempts: 2,
+            jitter_ns: 0,
+        })
+    }
+
+    #[rstest]
+    fn test_non_retryable_reason_never_resubmits(order_modify_rejected: OrderModifyRejected) {
+        let mut policy = test_policy();
+        let decision = policy.on_reject(&order_modify_rejected, 0.into(), 0.5);
+        assert_eq!(decision, RetryDecision::NonRetryable);
+    }
+
+    #[rstest]
+    fn test_delay_backs_off_exponentially() {
+        let policy = test_policy();
+        let first = policy.compute_delay_ns(1, 0.5);
+        let second = policy.compute_delay_ns(2, 0.5);
+        assert!(second > first);
+    }
+
+    #[rstest]
+    fn test_retryable_reason_resubmits_then_exhausts() {
+        let mut policy = test_policy();
+
+        let first_reject = order_modify_rejected_rate_limited(1.into());
+        let decision = policy.on_reject(&first_reject, 0.into(), 0.0);
+        assert_eq!(
+            decision,
+            RetryDecision::Resubmit {
+                attempt: 1,
+                retry_at: policy.compute_delay_ns(1, 0.0).into(),
+            }
+        );
+
+        let second_reject = order_modify_rejected_rate_limited(2.into());
+        let decision = policy.on_reject(&second_reject, 0.into(), 0.0);
+        assert_eq!(
+            decision,
+            RetryDecision::Resubmit {
+                attempt: 2,
+                retry_at: policy.compute_delay_ns(2, 0.0).into(),
+            }
+        );
+
+        // max_attempts is 2, so a third rejection (even with a fresh ts_event) is exhausted.
+        let third_reject = order_modify_rejected_rate_limited(3.into());
+        let decision = policy.on_reject(&third_reject, 0.into(), 0.0);
+        assert_eq!(decision, RetryDecision::AttemptsExhausted);
+
+        // Exhaustion evicts the entry so the map stays bounded to in-flight orders.
+        assert!(policy.states.is_empty());
+    }
+
+    #[rstest]
+    fn test_non_retryable_reject_evicts_existing_state(order_modify_rejected: OrderModifyRejected) {
+        let mut policy = test_policy();
+
+        let retryable = order_modify_rejected_rate_limited(1.into());
+        policy.on_reject(&retryable, 0.into(), 0.0);
+        assert_eq!(policy.states.len(), 1);
+
+        // Same order (same client_order_id/venue_order_id), but this time rejected for a
+        // non-retryable reason - terminal for retry purposes, so the entry must be evicted
+        // rather than left to linger.
+        let decision = policy.on_reject(&order_modify_rejected, 0.into(), 0.0);
+        assert_eq!(decision, RetryDecision::NonRetryable);
+        assert!(policy.states.is_empty());
+    }
+
+    #[rstest]
+    fn test_terminal_event_evicts_retry_state() {
+        let mut policy = test_policy();
+        let client_order_id = ClientOrderId::from("O-19700101-0000-000-001-1");
+        let venue_order_id = Some(VenueOrderId::from("001"));
+
+        let reject = order_modify_rejected_rate_limited(10.into());
+        let decision = policy.on_reject(&reject, 0.into(), 0.0);
+        assert!(matches!(decision, RetryDecision::Resubmit { attempt: 1, .. }));
+        assert_eq!(policy.states.len(), 1);
+
+        policy.observe_terminal_event(client_order_id, venue_order_id);
+        assert!(policy.states.is_empty());
+    }
+
+    #[rstest]
+    fn test_out_of_order_reject_is_fenced_by_high_water_mark() {
+        let mut policy = test_policy();
+
+        let later_reject = order_modify_rejected_rate_limited(100.into());
+        let decision = policy.on_reject(&later_reject, 0.into(), 0.0);
+        assert!(matches!(decision, RetryDecision::Resubmit { attempt: 1, .. }));
+
+        // Same order, delivered out of order: an earlier `ts_event` than what has already been
+        // observed must not be treated as an independent rejection.
+        let stale_reject = order_modify_rejected_rate_limited(50.into());
+        let decision = policy.on_reject(&stale_reject, 0.into(), 0.0);
+        assert_eq!(decision, RetryDecision::NonRetryable);
+
+        // Still in-flight (not terminal), so the entry remains tracked.
+        assert_eq!(policy.states.len(), 1);
+    }
+
+    #[rstest]
+    fn test_first_reject_with_zero_ts_event_is_not_suppressed() {
+        let mut policy = test_policy();
+        let reject = order_modify_rejected_rate_limited(0.into());
+        let decision = policy.on_reject(&reject, 0.into(), 0.0);
+        assert!(matches!(decision, RetryDecision::Resubmit { attempt: 1, .. }));
+    }
+}
@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sinks for publishing [`OrderEventRecord`] rows to an external consumer (message bus, columnar
+//! store, or anything else an indexer reads from), decoupling the event model from how it is
+//! ultimately persisted.
+
+use std::io::{self, Write};
+
+use super::record::OrderEventRecord;
+
+/// A destination for flattened order event records.
+///
+/// Implementations are expected to be cheap to call per-event; buffering or batching, if needed,
+/// is the implementation's responsibility.
+pub trait OrderEventSink {
+    /// The error type returned when a record cannot be published.
+    type Error;
+
+    /// Publishes a single record to this sink.
+    fn publish(&mut self, record: &OrderEventRecord) -> Result<(), Self::Error>;
+}
+
+/// An [`OrderEventSink`] that writes one JSON object per line to any [`Write`] destination,
+/// suitable for log shipping to a message bus or line-oriented ingestion pipeline.
+pub struct JsonLinesSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    /// Creates a new [`JsonLinesSink`] writing to `writer`.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> OrderEventSink for JsonLinesSink<W> {
+    type Error = io::Error;
+
+    fn publish(&mut self, record: &OrderEventRecord) -> Result<(), Self::Error> {
+        let line = serde_json::to_string(record)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// An [`OrderEventSink`] that accumulates records into an Arrow `RecordBatch` once `batch_size`
+/// rows have been buffered, for columnar analytics stores.
+///
+/// The schema covers every [`OrderEventRecord`] field: identifiers and classification columns are
+/// encoded via each value's `Display` impl (so enums and identifier newtypes round-trip as their
+/// canonical string form), [`Price`](crate::types::price::Price)/[`Quantity`](crate::types::quantity::Quantity)
+/// as nullable `Float64`, and timestamps/flags in their natural Arrow type. [`JsonLinesSink`](super::JsonLinesSink)
+/// remains the reference encoding (it serializes the record's own `Serialize` impl directly); this
+/// module's job is only to project that same data into columns a columnar store can query.
+#[cfg(feature = "arrow")]
+pub mod arrow_batch {
+    use arrow::{
+        array::{ArrayRef, BooleanArray, Float64Array, StringArray, UInt64Array, UInt8Array},
+        datatypes::{DataType, Field, Schema},
+        record_batch::RecordBatch,
+    };
+
+    use super::{OrderEventRecord, OrderEventSink};
+
+    /// Buffers [`OrderEventRecord`] rows and flushes them into Arrow [`RecordBatch`]es.
+    pub struct ArrowRecordBatchSink<F: FnMut(RecordBatch)> {
+        schema: Schema,
+        batch_size: usize,
+        buffer: Vec<OrderEventRecord>,
+        on_batch: F,
+    }
+
+    impl<F: FnMut(RecordBatch)> ArrowRecordBatchSink<F> {
+        /// Creates a new sink that flushes a batch once `batch_size` records have accumulated,
+        /// invoking `on_batch` with each completed [`RecordBatch`].
+        pub fn new(batch_size: usize, on_batch: F) -> Self {
+            let schema = Schema::new(vec![
+                Field::new("schema_version", DataType::UInt8, false),
+                Field::new("kind", DataType::Utf8, false),
+                Field::new("trader_id", DataType::Utf8, false),
+                Field::new("strategy_id", DataType::Utf8, false),
+                Field::new("instrument_id", DataType::Utf8, false),
+                Field::new("client_order_id", DataType::Utf8, false),
+                Field::new("venue_order_id", DataType::Utf8, true),
+                Field::new("account_id", DataType::Utf8, true),
+                Field::new("event_id", DataType::Utf8, false),
+                Field::new("ts_event", DataType::UInt64, false),
+                Field::new("ts_init", DataType::UInt64, false),
+                Field::new("order_type", DataType::Utf8, true),
+                Field::new("order_side", DataType::Utf8, true),
+                Field::new("reason", DataType::Utf8, true),
+                Field::new("reason_code", DataType::Utf8, false),
+                Field::new("event_source", DataType::Utf8, false),
+                Field::new("quantity", DataType::Float64, true),
+                Field::new("time_in_force", DataType::Utf8, true),
+                Field::new("post_only", DataType::Boolean, true),
+                Field::new("reduce_only", DataType::Boolean, true),
+                Field::new("quote_quantity", DataType::Boolean, true),
+                Field::new("reconciliation", DataType::Boolean, false),
+                Field::new("price", DataType::Float64, true),
+                Field::new("trigger_price", DataType::Float64, true),
+                Field::new("trigger_type", DataType::Utf8, true),
+                Field::new("limit_offset", DataType::Float64, true),
+                Field::new("trailing_offset", DataType::Float64, true),
+                Field::new("trailing_offset_type", DataType::Utf8, true),
+                Field::new("expire_time", DataType::UInt64, true),
+                Field::new("display_qty", DataType::Float64, true),
+                Field::new("emulation_trigger", DataType::Utf8, true),
+                Field::new("trigger_instrument_id", DataType::Utf8, true),
+                Field::new("contingency_type", DataType::Utf8, true),
+                Field::new("order_list_id", DataType::Utf8, true),
+                Field::new("parent_order_id", DataType::Utf8, true),
+                Field::new("exec_algorithm_id", DataType::Utf8, true),
+                Field::new("exec_spawn_id", DataType::Utf8, true),
+                Field::new("trade_id", DataType::Utf8, true),
+                Field::new("currency", DataType::Utf8, true),
+                Field::new("liquidity_side", DataType::Utf8, true),
+                Field::new("last_px", DataType::Float64, true),
+                Field::new("last_qty", DataType::Float64, true),
+            ]);
+
+            Self {
+                schema,
+                batch_size: batch_size.max(1),
+                buffer: Vec::with_capacity(batch_size),
+                on_batch,
+            }
+        }
+
+        /// Flushes any buffered records into a [`RecordBatch`], even if `batch_size` has not
+        /// been reached. No-op when the buffer is empty.
+        pub fn flush(&mut self) -> Result<(), arrow::error::ArrowError> {
+            if self.buffer.is_empty() {
+                return Ok(());
+            }
+
+            let utf8 = |f: fn(&OrderEventRecord) -> String| -> ArrayRef {
+                std::sync::Arc::new(StringArray::from(
+                    self.buffer.iter().map(f).collect::<Vec<_>>(),
+                ))
+            };
+            let utf8_opt = |f: fn(&OrderEventRecord) -> Option<String>| -> ArrayRef {
+                std::sync::Arc::new(StringArray::from(
+                    self.buffer.iter().map(f).collect::<Vec<_>>(),
+                ))
+            };
+            let f64_opt = |f: fn(&OrderEventRecord) -> Option<f64>| -> ArrayRef {
+                std::sync::Arc::new(Float64Array::from(
+                    self.buffer.iter().map(f).collect::<Vec<_>>(),
+                ))
+            };
+            let bool_opt = |f: fn(&OrderEventRecord) -> Option<bool>| -> ArrayRef {
+                std::sync::Arc::new(BooleanArray::from(
+                    self.buffer.iter().map(f).collect::<Vec<_>>(),
+                ))
+            };
+            let u64_opt = |f: fn(&OrderEventRecord) -> Option<u64>| -> ArrayRef {
+                std::sync::Arc::new(UInt64Array::from(
+                    self.buffer.iter().map(f).collect::<Vec<_>>(),
+                ))
+            };
+
+            let columns: Vec<ArrayRef> = vec![
+                std::sync::Arc::new(UInt8Array::from(
+                    self.buffer.iter().map(|r| r.schema_version).collect::<Vec<_>>(),
+                )),
+                utf8(|r| r.kind.to_string()),
+                utf8(|r| r.trader_id.to_string()),
+                utf8(|r| r.strategy_id.to_string()),
+                utf8(|r| r.instrument_id.to_string()),
+                utf8(|r| r.client_order_id.to_string()),
+                utf8_opt(|r| r.venue_order_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.account_id.map(|v| v.to_string())),
+                utf8(|r| r.event_id.clone()),
+                u64_opt(|r| Some(r.ts_event.as_u64())),
+                u64_opt(|r| Some(r.ts_init.as_u64())),
+                utf8_opt(|r| r.order_type.map(|v| v.to_string())),
+                utf8_opt(|r| r.order_side.map(|v| v.to_string())),
+                utf8_opt(|r| r.reason.map(|v| v.to_string())),
+                utf8(|r| r.reason_code.to_string()),
+                utf8(|r| r.event_source.to_string()),
+                f64_opt(|r| r.quantity.map(|v| v.as_f64())),
+                utf8_opt(|r| r.time_in_force.map(|v| v.to_string())),
+                bool_opt(|r| r.post_only),
+                bool_opt(|r| r.reduce_only),
+                bool_opt(|r| r.quote_quantity),
+                bool_opt(|r| Some(r.reconciliation)),
+                f64_opt(|r| r.price.map(|v| v.as_f64())),
+                f64_opt(|r| r.trigger_price.map(|v| v.as_f64())),
+                utf8_opt(|r| r.trigger_type.map(|v| v.to_string())),
+                f64_opt(|r| r.limit_offset.map(|v| v.as_f64())),
+                f64_opt(|r| r.trailing_offset.map(|v| v.as_f64())),
+                utf8_opt(|r| r.trailing_offset_type.map(|v| v.to_string())),
+                u64_opt(|r| r.expire_time.map(|v| v.as_u64())),
+                f64_opt(|r| r.display_qty.map(|v| v.as_f64())),
+                utf8_opt(|r| r.emulation_trigger.map(|v| v.to_string())),
+                utf8_opt(|r| r.trigger_instrument_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.contingency_type.map(|v| v.to_string())),
+                utf8_opt(|r| r.order_list_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.parent_order_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.exec_algorithm_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.exec_spawn_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.trade_id.map(|v| v.to_string())),
+                utf8_opt(|r| r.currency.map(|v| v.to_string())),
+                utf8_opt(|r| r.liquidity_side.map(|v| v.to_string())),
+                f64_opt(|r| r.last_px.map(|v| v.as_f64())),
+                f64_opt(|r| r.last_qty.map(|v| v.as_f64())),
+            ];
+
+            let batch = RecordBatch::try_new(std::sync::Arc::new(self.schema.clone()), columns)?;
+
+            self.buffer.clear();
+            (self.on_batch)(batch);
+            Ok(())
+        }
+    }
+
+    impl<F: FnMut(RecordBatch)> OrderEventSink for ArrowRecordBatchSink<F> {
+        type Error = arrow::error::ArrowError;
+
+        fn publish(&mut self, record: &OrderEventRecord) -> Result<(), Self::Error> {
+            self.buffer.push(record.clone());
+            if self.buffer.len() >= self.batch_size {
+                self.flush()?;
+            }
+            Ok(())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::{stubs::*, OrderEvent};
+
+    #[rstest]
+    fn test_json_lines_sink_writes_one_record_per_line(
+        order_modify_rejected: crate::events::order::modify_rejected::OrderModifyRejected,
+    ) {
+        let mut buf = Vec::new();
+        {
+            let mut sink = JsonLinesSink::new(&mut buf);
+            sink.publish(&order_modify_rejected.to_record()).unwrap();
+            sink.publish(&order_modify_rejected.to_record()).unwrap();
+        }
+        let text = String::from_utf8(buf).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text.contains("OrderModifyRejected"));
+    }
+}
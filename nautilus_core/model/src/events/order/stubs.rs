@@ -0,0 +1,70 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Test fixtures for order event unit tests.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use rstest::fixture;
+use ustr::Ustr;
+
+use crate::{
+    enums::{OrderEventSource, OrderRejectReason},
+    events::order::modify_rejected::OrderModifyRejected,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+};
+
+#[fixture]
+pub fn order_modify_rejected() -> OrderModifyRejected {
+    OrderModifyRejected::new(
+        TraderId::from("TRADER-001"),
+        StrategyId::from("S-001"),
+        InstrumentId::from("BTCUSDT.COINBASE"),
+        ClientOrderId::from("O-19700101-0000-000-001-1"),
+        Ustr::from("ORDER_DOES_NOT_EXIST"),
+        OrderRejectReason::OrderDoesNotExist,
+        UUID4::new(),
+        0.into(),
+        0.into(),
+        false,
+        OrderEventSource::Manual,
+        Some(VenueOrderId::from("001")),
+        Some(AccountId::from("SIM-001")),
+    )
+    .unwrap()
+}
+
+/// Builds an [`OrderModifyRejected`] classified as retryable (rate-limited), with `ts_event` set
+/// to `ts_event`, for exercising retry-policy progression across successive rejection deliveries.
+pub fn order_modify_rejected_rate_limited(ts_event: UnixNanos) -> OrderModifyRejected {
+    OrderModifyRejected::new(
+        TraderId::from("TRADER-001"),
+        StrategyId::from("S-001"),
+        InstrumentId::from("BTCUSDT.COINBASE"),
+        ClientOrderId::from("O-19700101-0000-000-001-1"),
+        Ustr::from("RATE_LIMIT_EXCEEDED"),
+        OrderRejectReason::RateLimited,
+        UUID4::new(),
+        ts_event,
+        ts_event,
+        false,
+        OrderEventSource::Manual,
+        Some(VenueOrderId::from("001")),
+        Some(AccountId::from("SIM-001")),
+    )
+    .unwrap()
+}